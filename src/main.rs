@@ -1,9 +1,16 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
+use clap_complete::{generate, Shell};
 use colored::*;
 use dirs;
 use reqwest;
+use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::Path;
+use std::pin::Pin;
 use std::process::{Command as StdCommand, Stdio};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,6 +26,84 @@ struct AurPackage {
     package_base: String,
     #[serde(rename = "Description")]
     description: Option<String>,
+    #[serde(rename = "Version")]
+    version: Option<String>,
+    #[serde(rename = "Depends")]
+    depends: Option<Vec<String>>,
+    #[serde(rename = "MakeDepends")]
+    make_depends: Option<Vec<String>>,
+}
+
+/// Open (creating if necessary) the local database of packages `void` has
+/// installed from the AUR, living alongside the build trees in
+/// `~/.void-builds/installed.db`.
+fn open_db() -> Result<Connection, Box<dyn std::error::Error>> {
+    let dir = dirs::home_dir().unwrap().join(".void-builds");
+    fs::create_dir_all(&dir)?;
+    let conn = Connection::open(dir.join("installed.db"))?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS installed (
+            name TEXT PRIMARY KEY,
+            package_base TEXT NOT NULL,
+            version TEXT NOT NULL,
+            install_date TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            installed_as_dep INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+    Ok(conn)
+}
+
+/// Record a successful AUR install, overwriting any previous row for `name`.
+fn record_install(
+    name: &str,
+    package_base: &str,
+    version: &str,
+    installed_as_dep: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = open_db()?;
+    conn.execute(
+        "INSERT INTO installed (name, package_base, version, installed_as_dep)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(name) DO UPDATE SET
+            package_base = excluded.package_base,
+            version = excluded.version,
+            install_date = CURRENT_TIMESTAMP,
+            installed_as_dep = excluded.installed_as_dep",
+        params![name, package_base, version, installed_as_dep as i32],
+    )?;
+    Ok(())
+}
+
+/// Drop the tracking row for `name` after it has been removed.
+fn forget_install(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = open_db()?;
+    conn.execute("DELETE FROM installed WHERE name = ?1", params![name])?;
+    Ok(())
+}
+
+/// Return every tracked AUR package as `(name, version)`.
+fn tracked_packages() -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+    let conn = open_db()?;
+    let mut stmt = conn.prepare("SELECT name, version FROM installed ORDER BY name")?;
+    let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+    let mut packages = Vec::new();
+    for row in rows {
+        packages.push(row?);
+    }
+    Ok(packages)
+}
+
+fn query_packages() -> Result<(), Box<dyn std::error::Error>> {
+    let packages = tracked_packages()?;
+    if packages.is_empty() {
+        println!("{}", "No AUR packages tracked".green());
+        return Ok(());
+    }
+    for (name, version) in packages {
+        println!("{} {}", name.bright_green().bold(), version.dimmed());
+    }
+    Ok(())
 }
 
 fn cli() -> Command {
@@ -27,6 +112,13 @@ fn cli() -> Command {
         .version("0.2")
         .arg_required_else_help(true)
         .subcommand_required(true)
+        .arg(
+            Arg::new("noconfirm")
+                .long("noconfirm")
+                .help("Skip all confirmation prompts")
+                .global(true)
+                .action(ArgAction::SetTrue),
+        )
         .subcommand(
             Command::new("sync")
                 .about("Synchronize packages")
@@ -35,12 +127,48 @@ fn cli() -> Command {
                     Command::new("search")
                         .about("Search packages")
                         .short_flag('s')
-                        .arg(Arg::new("query").required(true)),
+                        .arg(Arg::new("query").required(true))
+                        .arg(
+                            Arg::new("install")
+                                .long("install")
+                                .help("Pick results from a numbered menu and install them")
+                                .action(ArgAction::SetTrue),
+                        )
+                        .arg(
+                            Arg::new("sudoloop")
+                                .long("sudoloop")
+                                .help("Keep sudo alive with a background refresh during the build")
+                                .action(ArgAction::SetTrue),
+                        ),
                 )
                 .subcommand(
                     Command::new("install")
                         .about("Install package")
-                        .arg(Arg::new("package").required(true)),
+                        .arg(Arg::new("package").required(true))
+                        .arg(
+                            Arg::new("sudoloop")
+                                .long("sudoloop")
+                                .help("Keep sudo alive with a background refresh during the build")
+                                .action(ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("upgrade")
+                        .about("Upgrade installed AUR packages")
+                        .short_flag('u')
+                        .arg(
+                            Arg::new("sudoloop")
+                                .long("sudoloop")
+                                .help("Keep sudo alive with a background refresh during the build")
+                                .action(ArgAction::SetTrue),
+                        ),
+                )
+                .arg(
+                    Arg::new("refresh")
+                        .short('y')
+                        .long("refresh")
+                        .help("Accepted for pacman parity; package databases are not refreshed")
+                        .action(ArgAction::SetTrue),
                 ),
         )
         .subcommand(
@@ -49,6 +177,27 @@ fn cli() -> Command {
                 .short_flag('R')
                 .arg(Arg::new("package").required(true)),
         )
+        .subcommand(
+            Command::new("query")
+                .about("List tracked AUR packages")
+                .short_flag('Q')
+                .arg(
+                    Arg::new("foreign")
+                        .short('m')
+                        .long("foreign")
+                        .help("List foreign (AUR) packages (accepted for pacman parity)")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about("Generate shell completion scripts")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_parser(clap::value_parser!(Shell)),
+                ),
+        )
 }
 
 async fn get_package_info(package: &str) -> Result<Option<AurPackage>, Box<dyn std::error::Error>> {
@@ -57,6 +206,95 @@ async fn get_package_info(package: &str) -> Result<Option<AurPackage>, Box<dyn s
     Ok(response.results.into_iter().next())
 }
 
+async fn get_packages_info(
+    packages: &[String],
+) -> Result<Vec<AurPackage>, Box<dyn std::error::Error>> {
+    if packages.is_empty() {
+        return Ok(Vec::new());
+    }
+    let args: String = packages.iter().map(|p| format!("&arg[]={}", p)).collect();
+    let url = format!("https://aur.archlinux.org/rpc/?v=5&type=info{}", args);
+    let response = reqwest::get(&url).await?.json::<AurResponse>().await?;
+    Ok(response.results)
+}
+
+/// Return true when `installed` sorts older than `candidate` per pacman's
+/// `vercmp`. If `vercmp` is unavailable or unparseable we can't truly compare,
+/// so warn and fall back to a plain string inequality rather than silently
+/// claiming the package is up to date.
+fn is_outdated(installed: &str, candidate: &str) -> bool {
+    match StdCommand::new("vercmp").arg(installed).arg(candidate).output() {
+        Ok(out) => match String::from_utf8_lossy(&out.stdout).trim().parse::<i32>() {
+            Ok(order) => order < 0,
+            Err(_) => {
+                eprintln!("{}", "Warning: could not parse vercmp output".yellow());
+                installed != candidate
+            }
+        },
+        Err(_) => {
+            eprintln!("{}", "Warning: vercmp unavailable, comparing versions as strings".yellow());
+            installed != candidate
+        }
+    }
+}
+
+/// Ask the user a yes/no question, defaulting to yes on an empty answer.
+fn confirm(prompt: &str) -> bool {
+    print!("{} [Y/n] ", prompt);
+    io::stdout().flush().ok();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    let answer = input.trim().to_lowercase();
+    answer.is_empty() || answer == "y" || answer == "yes"
+}
+
+async fn upgrade_packages(
+    noconfirm: bool,
+    sudoloop: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let installed = tracked_packages()?;
+    if installed.is_empty() {
+        println!("{}", "No AUR packages tracked".green());
+        return Ok(());
+    }
+
+    let names: Vec<String> = installed.iter().map(|(name, _)| name.clone()).collect();
+    let remote = get_packages_info(&names).await?;
+
+    let mut upgrades = Vec::new();
+    for (name, old) in &installed {
+        if let Some(pkg) = remote.iter().find(|p| &p.name == name) {
+            if let Some(new) = &pkg.version {
+                if is_outdated(old, new) {
+                    upgrades.push((name.clone(), old.clone(), new.clone()));
+                }
+            }
+        }
+    }
+
+    if upgrades.is_empty() {
+        println!("{}", "All AUR packages are up to date".green());
+        return Ok(());
+    }
+
+    println!("{}", "Packages to upgrade:".green());
+    for (name, old, new) in &upgrades {
+        println!("  {} {} -> {}", name.bold(), old.red(), new.bright_green());
+    }
+
+    if !noconfirm && !confirm("Proceed with upgrade?") {
+        return Ok(());
+    }
+
+    for (name, _, _) in &upgrades {
+        install_package(name, noconfirm, sudoloop).await?;
+    }
+
+    Ok(())
+}
+
 async fn search_packages(query: &str) -> Result<Vec<AurPackage>, Box<dyn std::error::Error>> {
     let url = format!("https://aur.archlinux.org/rpc/?v=5&type=search&arg={}", query);
     let response = reqwest::get(&url).await?.json::<AurResponse>().await?;
@@ -112,47 +350,349 @@ async fn show_search_results(query: &str) -> Result<(), Box<dyn std::error::Erro
     Ok(())
 }
 
-async fn install_package(package: &str) -> Result<(), Box<dyn std::error::Error>> {
-    match get_package_info(package).await? {
-        Some(pkg) => {
-            println!("{} {}", "Installing:".bright_green(), pkg.name.bold());
-            
-            let build_dir = dirs::home_dir()
-                .unwrap()
-                .join(".void-builds")
-                .join(&pkg.package_base);
-            
-            if build_dir.exists() {
-                fs::remove_dir_all(&build_dir)?;
-            }
+/// Strip a pacman dependency constraint (`foo>=1.2`) down to its bare name.
+fn strip_version_constraint(dep: &str) -> String {
+    dep.split(['>', '<', '='])
+        .next()
+        .unwrap_or(dep)
+        .trim()
+        .to_string()
+}
 
-            fs::create_dir_all(&build_dir)?;
+/// Boxed, lifetime-bound future produced by the recursive dependency resolver.
+type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<(), Box<dyn std::error::Error>>> + 'a>>;
 
-            let aur_url = format!("https://aur.archlinux.org/{}.git", pkg.package_base);
-            
-            if !StdCommand::new("git")
-                .arg("clone")
-                .arg(&aur_url)
-                .arg(&build_dir)
-                .stdout(Stdio::inherit())
-                .stderr(Stdio::inherit())
-                .status()?
-                .success() {
-                eprintln!("{}", "Failed to clone repository".red());
-                return Ok(());
+/// Recursively resolve the dependencies of `package`, classifying each into
+/// repository packages (installable via `pacman -S`) and AUR packages (present
+/// in the RPC). Collects the repo dependencies into `repo_deps` and appends
+/// a topologically ordered list of `(name, package_base, version)` AUR builds
+/// to `build_order` — dependencies first, the target last. Already-visited names and package bases are deduped and
+/// cycles are broken with an in-progress stack.
+fn resolve_dependencies<'a>(
+    name: &'a str,
+    visited: &'a mut HashSet<String>,
+    stack: &'a mut HashSet<String>,
+    repo_deps: &'a mut HashSet<String>,
+    build_order: &'a mut Vec<(String, String, String)>,
+    seen_bases: &'a mut HashSet<String>,
+) -> ResolveFuture<'a> {
+    Box::pin(async move {
+        if visited.contains(name) {
+            return Ok(());
+        }
+        if !stack.insert(name.to_string()) {
+            // Dependency cycle — stop descending here.
+            return Ok(());
+        }
+
+        match get_package_info(name).await? {
+            Some(pkg) => {
+                let dep_names: Vec<String> = pkg
+                    .depends
+                    .clone()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(pkg.make_depends.clone().unwrap_or_default())
+                    .map(|d| strip_version_constraint(&d))
+                    .collect();
+
+                let aur = get_packages_info(&dep_names).await?;
+                let aur_names: HashSet<String> =
+                    aur.iter().map(|p| p.name.clone()).collect();
+
+                for dep in &dep_names {
+                    if aur_names.contains(dep) {
+                        resolve_dependencies(
+                            dep, visited, stack, repo_deps, build_order, seen_bases,
+                        )
+                        .await?;
+                    } else {
+                        repo_deps.insert(dep.clone());
+                    }
+                }
+
+                if seen_bases.insert(pkg.package_base.clone()) {
+                    build_order.push((
+                        pkg.name.clone(),
+                        pkg.package_base.clone(),
+                        pkg.version.clone().unwrap_or_default(),
+                    ));
+                }
             }
+            None => {
+                // Unknown to the AUR — assume it is a repo package.
+                repo_deps.insert(name.to_string());
+            }
+        }
+
+        stack.remove(name);
+        visited.insert(name.to_string());
+        Ok(())
+    })
+}
+
+/// Install repository dependencies in a single `pacman -S --asdeps` call.
+fn install_repo_dependencies(deps: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {}", "Installing dependencies:".green(), deps.join(" "));
+    let status = StdCommand::new("sudo")
+        .arg("pacman")
+        .arg("-S")
+        .arg("--asdeps")
+        .arg("--needed")
+        .args(deps)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    if !status.success() {
+        eprintln!("{}", "Failed to install dependencies".red());
+        return Err("failed to install repository dependencies".into());
+    }
 
-            let status = StdCommand::new("makepkg")
-                .arg("-si")
-                .current_dir(&build_dir)
-                .stdout(Stdio::inherit())
-                .stderr(Stdio::inherit())
+    Ok(())
+}
+
+/// List the build files fetched from the AUR and, on request, open them in
+/// `$EDITOR` (falling back to `less`) before asking whether to continue. Lets
+/// the user inspect arbitrary PKGBUILD/`.install` code before it runs.
+fn review_build_files(build_dir: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(build_dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name == "PKGBUILD"
+            || file_name == ".SRCINFO"
+            || file_name.ends_with(".install")
+        {
+            files.push(entry.path());
+        }
+    }
+
+    if files.is_empty() {
+        return Ok(true);
+    }
+
+    println!("{}", "Files to review:".green());
+    for file in &files {
+        println!("  {}", file.file_name().unwrap_or_default().to_string_lossy());
+    }
+
+    if confirm("Review these files before building?") {
+        let editor = std::env::var("EDITOR").unwrap_or_else(|_| "less".to_string());
+        for file in &files {
+            StdCommand::new(&editor)
+                .arg(file)
                 .status()?;
+        }
+    }
+
+    Ok(confirm("Proceed with build?"))
+}
+
+/// Clone an AUR package base and build it with `makepkg -si`.
+async fn build_from_aur(
+    name: &str,
+    package_base: &str,
+    version: &str,
+    installed_as_dep: bool,
+    noconfirm: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} {}", "Installing:".bright_green(), name.bold());
+
+    let build_dir = dirs::home_dir()
+        .unwrap()
+        .join(".void-builds")
+        .join(package_base);
+
+    if build_dir.exists() {
+        fs::remove_dir_all(&build_dir)?;
+    }
+
+    fs::create_dir_all(&build_dir)?;
+
+    let aur_url = format!("https://aur.archlinux.org/{}.git", package_base);
+
+    if !StdCommand::new("git")
+        .arg("clone")
+        .arg(&aur_url)
+        .arg(&build_dir)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?
+        .success()
+    {
+        eprintln!("{}", "Failed to clone repository".red());
+        return Ok(());
+    }
+
+    if !noconfirm && !review_build_files(&build_dir)? {
+        println!("{} {}", "Skipped:".yellow(), name.bold());
+        return Ok(());
+    }
+
+    let mut makepkg = StdCommand::new("makepkg");
+    makepkg.arg("-si");
+    if noconfirm {
+        makepkg.arg("--noconfirm");
+    }
+    let status = makepkg
+        .current_dir(&build_dir)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()?;
+
+    if !status.success() {
+        eprintln!("{}", "Installation failed".red());
+    } else {
+        record_install(name, package_base, version, installed_as_dep)?;
+        println!("{} {}", "Success:".bright_green(), name.bold());
+    }
+
+    Ok(())
+}
+
+/// Background task that validates sudo up front and then re-validates every
+/// ~30 seconds so privilege escalation does not expire partway through a long
+/// build. It is aborted when the guard below is dropped.
+fn start_sudoloop() -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async {
+        let _ = StdCommand::new("sudo").arg("-v").status();
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+            let ok = StdCommand::new("sudo")
+                .arg("-v")
+                .status()
+                .map(|s| s.success())
+                .unwrap_or(false);
+            if !ok {
+                break;
+            }
+        }
+    })
+}
 
-            if !status.success() {
-                eprintln!("{}", "Installation failed".red());
+/// RAII guard that cancels the sudoloop task on any return path.
+struct SudoloopGuard(Option<tokio::task::JoinHandle<()>>);
+
+impl Drop for SudoloopGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.0.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Parse a selection string such as `1 3 5-7` into zero-based indices within
+/// `[0, max)`, preserving order and dropping duplicates and out-of-range entries.
+fn parse_selection(input: &str, max: usize) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    let mut indices = Vec::new();
+    let mut push = |n: usize, out: &mut Vec<usize>| {
+        if n >= 1 && n <= max && seen.insert(n) {
+            out.push(n - 1);
+        }
+    };
+
+    for token in input.split_whitespace() {
+        if let Some((a, b)) = token.split_once('-') {
+            if let (Ok(start), Ok(end)) = (a.parse::<usize>(), b.parse::<usize>()) {
+                // Clamp the upper bound so a typo like `1-99999999999` can't
+                // spin the loop ~10^11 times at the prompt.
+                if start <= max {
+                    for n in start..=end.min(max) {
+                        push(n, &mut indices);
+                    }
+                }
+            }
+        } else if let Ok(n) = token.parse::<usize>() {
+            push(n, &mut indices);
+        }
+    }
+
+    indices
+}
+
+/// Print the search results as a numbered menu, prompt for a selection, and
+/// install each chosen package through the dependency-resolving install flow.
+async fn interactive_install(
+    query: &str,
+    noconfirm: bool,
+    sudoloop: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let packages = search_packages(query).await?;
+
+    if packages.is_empty() {
+        println!("{} {}", "No packages found for:".red(), query);
+        return Ok(());
+    }
+
+    for (index, pkg) in packages.iter().enumerate() {
+        println!(
+            "{} {} - {}",
+            format!("{})", index + 1).bold(),
+            pkg.name.bright_green().bold(),
+            pkg.description.as_deref().unwrap_or("No description").dimmed()
+        );
+    }
+
+    print!("{} ", "Select packages to install (e.g. 1 3 5-7):".green());
+    io::stdout().flush().ok();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    let selection = parse_selection(&input, packages.len());
+    if selection.is_empty() {
+        println!("{}", "Nothing selected".yellow());
+        return Ok(());
+    }
+
+    for index in selection {
+        install_package(&packages[index].name, noconfirm, sudoloop).await?;
+    }
+
+    Ok(())
+}
+
+async fn install_package(
+    package: &str,
+    noconfirm: bool,
+    sudoloop: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match get_package_info(package).await? {
+        Some(_) => {
+            let mut visited = HashSet::new();
+            let mut stack = HashSet::new();
+            let mut repo_deps = HashSet::new();
+            let mut build_order = Vec::new();
+            let mut seen_bases = HashSet::new();
+
+            resolve_dependencies(
+                package,
+                &mut visited,
+                &mut stack,
+                &mut repo_deps,
+                &mut build_order,
+                &mut seen_bases,
+            )
+            .await?;
+
+            let _sudoloop = if sudoloop {
+                Some(SudoloopGuard(Some(start_sudoloop())))
             } else {
-                println!("{} {}", "Success:".bright_green(), pkg.name.bold());
+                None
+            };
+
+            if !repo_deps.is_empty() {
+                let deps: Vec<String> = repo_deps.into_iter().collect();
+                install_repo_dependencies(&deps)?;
+            }
+
+            let last = build_order.len().saturating_sub(1);
+            for (index, (name, package_base, version)) in build_order.iter().enumerate() {
+                // Everything resolved before the final target is a dependency.
+                let installed_as_dep = index != last;
+                build_from_aur(name, package_base, version, installed_as_dep, noconfirm).await?;
             }
         }
         None => {
@@ -178,6 +718,7 @@ async fn remove_package(package: &str) -> Result<(), Box<dyn std::error::Error>>
     if !status.success() {
         eprintln!("{}", "Removal failed".red());
     } else {
+        forget_install(package)?;
         println!("{} {}", "Removed:".bright_green(), package.bold());
     }
 
@@ -187,20 +728,42 @@ async fn remove_package(package: &str) -> Result<(), Box<dyn std::error::Error>>
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let matches = cli().get_matches();
+    let noconfirm = matches.get_flag("noconfirm");
 
     match matches.subcommand() {
         Some(("sync", sync_matches)) => match sync_matches.subcommand() {
             Some(("search", search_matches)) => {
-                show_search_results(search_matches.get_one::<String>("query").unwrap()).await?
+                let query = search_matches.get_one::<String>("query").unwrap();
+                if search_matches.get_flag("install") {
+                    interactive_install(query, noconfirm, search_matches.get_flag("sudoloop"))
+                        .await?
+                } else {
+                    show_search_results(query).await?
+                }
             }
             Some(("install", install_matches)) => {
-                install_package(install_matches.get_one::<String>("package").unwrap()).await?
+                install_package(
+                    install_matches.get_one::<String>("package").unwrap(),
+                    noconfirm,
+                    install_matches.get_flag("sudoloop"),
+                )
+                .await?
+            }
+            Some(("upgrade", upgrade_matches)) => {
+                upgrade_packages(noconfirm, upgrade_matches.get_flag("sudoloop")).await?
             }
             _ => eprintln!("{}", "Invalid sync command".red()),
         },
         Some(("remove", remove_matches)) => {
             remove_package(remove_matches.get_one::<String>("package").unwrap()).await?
         }
+        Some(("query", _)) => query_packages()?,
+        Some(("completions", completions_matches)) => {
+            let shell = *completions_matches.get_one::<Shell>("shell").unwrap();
+            let mut cmd = cli();
+            let name = cmd.get_name().to_string();
+            generate(shell, &mut cmd, name, &mut io::stdout());
+        }
         _ => eprintln!("{}", "Invalid command".red()),
     }
 